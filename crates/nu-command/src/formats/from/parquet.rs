@@ -0,0 +1,393 @@
+use nu_engine::CallExt;
+use nu_protocol::ast::Call;
+use nu_protocol::engine::{Command, EngineState, Stack};
+use nu_protocol::{
+    Category, IntoPipelineData, PipelineData, ShellError, Signature, Span, Spanned, SyntaxShape,
+    Type, Value,
+};
+
+use arrow::array::Array;
+use arrow::datatypes::{DataType, TimeUnit};
+use arrow::error::ArrowError;
+use arrow::record_batch::RecordBatch;
+use bytes::Bytes;
+use chrono::{FixedOffset, NaiveDate, NaiveDateTime, TimeZone, Utc};
+use parquet::arrow::arrow_reader::ParquetRecordBatchReaderBuilder;
+use parquet::errors::ParquetError;
+use std::fs::File;
+use std::path::PathBuf;
+
+#[derive(Clone)]
+pub struct FromParquet;
+
+impl Command for FromParquet {
+    fn name(&self) -> &str {
+        "from parquet"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("from parquet")
+            .named(
+                "file",
+                SyntaxShape::Filepath,
+                "file path to read the parquet file from",
+                Some('f'),
+            )
+            .input_output_types(vec![(Type::Any, Type::Table(vec![]))])
+            .category(Category::Formats)
+    }
+
+    fn usage(&self) -> &str {
+        "Parse a parquet file and create a table"
+    }
+
+    fn run(
+        &self,
+        engine_state: &EngineState,
+        stack: &mut Stack,
+        call: &Call,
+        input: PipelineData,
+    ) -> Result<PipelineData, ShellError> {
+        let head = call.head;
+        let file: Option<Spanned<PathBuf>> = call.get_flag(engine_state, stack, "file")?;
+        from_parquet(input, file, head)
+    }
+}
+
+fn from_parquet(
+    input: PipelineData,
+    file: Option<Spanned<PathBuf>>,
+    head: Span,
+) -> Result<PipelineData, ShellError> {
+    let rows = match file {
+        Some(file) => {
+            let reader = File::open(&file.item).map_err(|error| {
+                ShellError::GenericError(
+                    "Could not open parquet file".to_string(),
+                    error.to_string(),
+                    Some(file.span),
+                    None,
+                    Vec::new(),
+                )
+            })?;
+            parquet_reader_to_values(reader, head)
+        }
+        None => {
+            let value = input.into_value(head);
+            match value {
+                Value::Binary { val, .. } => parquet_reader_to_values(Bytes::from(val), head),
+                Value::String { val, .. } => {
+                    let reader = File::open(&val).map_err(|error| {
+                        ShellError::GenericError(
+                            "Could not open parquet file".to_string(),
+                            error.to_string(),
+                            Some(head),
+                            None,
+                            Vec::new(),
+                        )
+                    })?;
+                    parquet_reader_to_values(reader, head)
+                }
+                other => Err(ShellError::UnsupportedInput(
+                    "Expected a filepath or binary input from a parquet file".to_string(),
+                    other.span().unwrap_or(head),
+                )),
+            }
+        }
+    }?;
+
+    Ok(Value::List { vals: rows, span: head }.into_pipeline_data())
+}
+
+fn parquet_reader_to_values<R>(reader: R, span: Span) -> Result<Vec<Value>, ShellError>
+where
+    R: parquet::file::reader::ChunkReader + 'static,
+{
+    let reader_builder = ParquetRecordBatchReaderBuilder::try_new(reader)
+        .map_err(|error| parquet_error_to_shell_error(error, span))?;
+    let record_batch_reader = reader_builder
+        .build()
+        .map_err(|error| parquet_error_to_shell_error(error, span))?;
+
+    let mut rows = Vec::new();
+    for batch in record_batch_reader {
+        let batch: RecordBatch =
+            batch.map_err(|error: ArrowError| arrow_error_to_shell_error(error, span))?;
+        rows.extend(record_batch_to_values(&batch, span)?);
+    }
+    Ok(rows)
+}
+
+fn parquet_error_to_shell_error(error: ParquetError, span: Span) -> ShellError {
+    ShellError::GenericError(
+        "Failed to read parquet data".to_string(),
+        error.to_string(),
+        Some(span),
+        None,
+        Vec::new(),
+    )
+}
+
+fn arrow_error_to_shell_error(error: ArrowError, span: Span) -> ShellError {
+    ShellError::GenericError(
+        "Failed to read parquet data".to_string(),
+        error.to_string(),
+        Some(span),
+        None,
+        Vec::new(),
+    )
+}
+
+fn record_batch_to_values(batch: &RecordBatch, span: Span) -> Result<Vec<Value>, ShellError> {
+    let cols: Vec<String> = batch
+        .schema()
+        .fields()
+        .iter()
+        .map(|field| field.name().clone())
+        .collect();
+
+    (0..batch.num_rows())
+        .map(|row| {
+            let vals = batch
+                .columns()
+                .iter()
+                .map(|column| arrow_value_to_nu(column.as_ref(), row, span))
+                .collect::<Result<Vec<_>, _>>()?;
+            Ok(Value::Record {
+                cols: cols.clone(),
+                vals,
+                span,
+            })
+        })
+        .collect()
+}
+
+fn arrow_value_to_nu(array: &dyn Array, row: usize, span: Span) -> Result<Value, ShellError> {
+    if array.is_null(row) {
+        return Ok(Value::Nothing { span });
+    }
+
+    macro_rules! primitive {
+        ($array_ty:ty) => {
+            array
+                .as_any()
+                .downcast_ref::<$array_ty>()
+                .expect("arrow array type mismatch")
+                .value(row)
+        };
+    }
+
+    let value = match array.data_type() {
+        DataType::Boolean => Value::Bool {
+            val: primitive!(arrow::array::BooleanArray),
+            span,
+        },
+        DataType::Int8 => Value::Int {
+            val: primitive!(arrow::array::Int8Array) as i64,
+            span,
+        },
+        DataType::Int16 => Value::Int {
+            val: primitive!(arrow::array::Int16Array) as i64,
+            span,
+        },
+        DataType::Int32 => Value::Int {
+            val: primitive!(arrow::array::Int32Array) as i64,
+            span,
+        },
+        DataType::Int64 => Value::Int {
+            val: primitive!(arrow::array::Int64Array),
+            span,
+        },
+        DataType::UInt8 => Value::Int {
+            val: primitive!(arrow::array::UInt8Array) as i64,
+            span,
+        },
+        DataType::UInt16 => Value::Int {
+            val: primitive!(arrow::array::UInt16Array) as i64,
+            span,
+        },
+        DataType::UInt32 => Value::Int {
+            val: primitive!(arrow::array::UInt32Array) as i64,
+            span,
+        },
+        DataType::UInt64 => Value::Int {
+            val: primitive!(arrow::array::UInt64Array) as i64,
+            span,
+        },
+        DataType::Float32 => Value::Float {
+            val: primitive!(arrow::array::Float32Array) as f64,
+            span,
+        },
+        DataType::Float64 => Value::Float {
+            val: primitive!(arrow::array::Float64Array),
+            span,
+        },
+        DataType::Utf8 => Value::String {
+            val: primitive!(arrow::array::StringArray).to_string(),
+            span,
+        },
+        DataType::LargeUtf8 => Value::String {
+            val: primitive!(arrow::array::LargeStringArray).to_string(),
+            span,
+        },
+        DataType::Date32 => {
+            let days = primitive!(arrow::array::Date32Array);
+            date32_to_value(days, span)
+        }
+        DataType::Date64 => {
+            let millis = primitive!(arrow::array::Date64Array);
+            millis_to_value(millis, span)
+        }
+        DataType::Timestamp(unit, _) => {
+            let millis = match unit {
+                TimeUnit::Second => primitive!(arrow::array::TimestampSecondArray) * 1_000,
+                TimeUnit::Millisecond => primitive!(arrow::array::TimestampMillisecondArray),
+                TimeUnit::Microsecond => primitive!(arrow::array::TimestampMicrosecondArray) / 1_000,
+                TimeUnit::Nanosecond => primitive!(arrow::array::TimestampNanosecondArray) / 1_000_000,
+            };
+            millis_to_value(millis, span)
+        }
+        other => {
+            return Err(ShellError::UnsupportedInput(
+                format!("Unsupported parquet column type '{:?}'", other),
+                span,
+            ))
+        }
+    };
+
+    Ok(value)
+}
+
+fn date32_to_value(days: i32, span: Span) -> Value {
+    match NaiveDate::from_ymd_opt(1970, 1, 1).and_then(|epoch| epoch.checked_add_signed(
+        chrono::Duration::days(days as i64),
+    )) {
+        Some(date) => millis_to_value(
+            date.and_hms_opt(0, 0, 0)
+                .unwrap_or_default()
+                .timestamp_millis(),
+            span,
+        ),
+        None => Value::Nothing { span },
+    }
+}
+
+fn millis_to_value(millis: i64, span: Span) -> Value {
+    let naive: Option<NaiveDateTime> = NaiveDateTime::from_timestamp_millis(millis);
+    match naive {
+        Some(naive) => {
+            let date_time = Utc.from_utc_datetime(&naive);
+            Value::Date {
+                val: date_time.with_timezone(&FixedOffset::east_opt(0).expect("valid offset")),
+                span,
+            }
+        }
+        None => Value::Nothing { span },
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use arrow::array::{BinaryArray, Date32Array, Float32Array, Int32Array, TimestampMicrosecondArray};
+    use arrow::datatypes::{Field, Schema};
+    use std::sync::Arc;
+
+    #[test]
+    fn record_batch_to_values_converts_arrow_types_and_nulls() {
+        let span = Span::test_data();
+        let schema = Arc::new(Schema::new(vec![
+            Field::new("id", DataType::Int32, true),
+            Field::new("amount", DataType::Float32, true),
+            Field::new("created", DataType::Date32, true),
+            Field::new(
+                "updated",
+                DataType::Timestamp(TimeUnit::Microsecond, None),
+                true,
+            ),
+        ]));
+        let batch = RecordBatch::try_new(
+            schema,
+            vec![
+                Arc::new(Int32Array::from(vec![Some(1), None])),
+                Arc::new(Float32Array::from(vec![Some(1.5), Some(2.5)])),
+                Arc::new(Date32Array::from(vec![Some(0), None])),
+                Arc::new(TimestampMicrosecondArray::from(vec![
+                    Some(1_000_000),
+                    Some(2_000_000),
+                ])),
+            ],
+        )
+        .expect("batch should build");
+
+        let rows = record_batch_to_values(&batch, span).expect("conversion should succeed");
+        assert_eq!(rows.len(), 2);
+
+        match &rows[0] {
+            Value::Record { cols, vals, .. } => {
+                assert_eq!(
+                    cols,
+                    &vec![
+                        "id".to_string(),
+                        "amount".to_string(),
+                        "created".to_string(),
+                        "updated".to_string()
+                    ]
+                );
+                assert!(matches!(vals[0], Value::Int { val: 1, .. }));
+                assert!(matches!(vals[1], Value::Float { val, .. } if (val - 1.5).abs() < f64::EPSILON));
+                match &vals[2] {
+                    Value::Date { val, .. } => assert_eq!(val.timestamp(), 0),
+                    other => panic!("expected a date, got {:?}", other),
+                }
+                match &vals[3] {
+                    Value::Date { val, .. } => assert_eq!(val.timestamp(), 1),
+                    other => panic!("expected a date, got {:?}", other),
+                }
+            }
+            other => panic!("expected a record, got {:?}", other),
+        }
+
+        match &rows[1] {
+            Value::Record { vals, .. } => {
+                assert!(matches!(vals[0], Value::Nothing { .. }));
+                assert!(matches!(vals[2], Value::Nothing { .. }));
+                match &vals[3] {
+                    Value::Date { val, .. } => assert_eq!(val.timestamp(), 2),
+                    other => panic!("expected a date, got {:?}", other),
+                }
+            }
+            other => panic!("expected a record, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn arrow_value_to_nu_errors_on_unsupported_type() {
+        let span = Span::test_data();
+        let array = BinaryArray::from(vec![Some(b"hi".as_ref())]);
+
+        let result = arrow_value_to_nu(&array, 0, span);
+
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn millis_to_value_converts_epoch_millis() {
+        let span = Span::test_data();
+
+        match millis_to_value(1_000, span) {
+            Value::Date { val, .. } => assert_eq!(val.timestamp(), 1),
+            other => panic!("expected a date, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn date32_to_value_converts_epoch_day() {
+        let span = Span::test_data();
+
+        match date32_to_value(0, span) {
+            Value::Date { val, .. } => assert_eq!(val.timestamp(), 0),
+            other => panic!("expected a date, got {:?}", other),
+        }
+    }
+}
@@ -10,8 +10,19 @@ use csv::WriterBuilder;
 use indexmap::{indexset, IndexSet};
 use std::collections::VecDeque;
 
+use arrow::array::{
+    ArrayRef, BooleanBuilder, Float64Builder, Int64Builder, StringBuilder,
+    TimestampMillisecondBuilder,
+};
 use arrow::csv::ReaderBuilder;
-use parquet::{arrow::ArrowWriter, errors::ParquetError};
+use arrow::datatypes::{DataType, Field, Schema, SchemaRef, TimeUnit};
+use arrow::record_batch::RecordBatch;
+use parquet::{
+    arrow::ArrowWriter,
+    basic::Compression,
+    errors::ParquetError,
+    file::properties::{WriterProperties, WriterVersion},
+};
 use std::sync::Arc;
 use std::{fs::File, path::PathBuf};
 
@@ -31,7 +42,31 @@ impl Command for ToParquet {
                 "file path to save parquet file",
                 Some('f'),
             )
-            .input_output_types(vec![(Type::Any, Type::String)])
+            .named(
+                "compression",
+                SyntaxShape::String,
+                "compression codec to use: snappy, gzip, brotli, lz4, zstd, or uncompressed (default: uncompressed)",
+                Some('c'),
+            )
+            .named(
+                "writer-version",
+                SyntaxShape::String,
+                "parquet writer version to target: 1.0 or 2.0 (default: 1.0)",
+                Some('w'),
+            )
+            .named(
+                "max-row-group-size",
+                SyntaxShape::Int,
+                "maximum number of rows per row group",
+                Some('m'),
+            )
+            .named(
+                "schema",
+                SyntaxShape::Any,
+                "path to a message-type schema file, or a record mapping column names to types (int, string, double, boolean, timestamp); skips type inference",
+                Some('s'),
+            )
+            .input_output_types(vec![(Type::Any, Type::String), (Type::Any, Type::Binary)])
             .category(Category::Formats)
     }
 
@@ -48,64 +83,650 @@ impl Command for ToParquet {
     ) -> Result<nu_protocol::PipelineData, ShellError> {
         let head = call.head;
         let file: Option<Spanned<PathBuf>> = call.get_flag(engine_state, stack, "file")?;
+        let compression: Option<Spanned<String>> =
+            call.get_flag(engine_state, stack, "compression")?;
+        let writer_version: Option<Spanned<String>> =
+            call.get_flag(engine_state, stack, "writer-version")?;
+        let max_row_group_size: Option<Spanned<i64>> =
+            call.get_flag(engine_state, stack, "max-row-group-size")?;
+        let schema: Option<Value> = call.get_flag(engine_state, stack, "schema")?;
         let config = engine_state.get_config();
-        to_parquet(input, file, head, config)
+        let props = build_writer_properties(compression, writer_version, max_row_group_size)?;
+        let schema_override = schema.map(|value| parse_schema_override(value, head)).transpose()?;
+        to_parquet(input, file, props, schema_override, head, config)
+    }
+}
+
+fn parse_compression(compression: &Spanned<String>) -> Result<Compression, ShellError> {
+    match compression.item.to_ascii_lowercase().as_str() {
+        "snappy" => Ok(Compression::SNAPPY),
+        "gzip" => Ok(Compression::GZIP(Default::default())),
+        "brotli" => Ok(Compression::BROTLI(Default::default())),
+        "lz4" => Ok(Compression::LZ4),
+        "zstd" => Ok(Compression::ZSTD(Default::default())),
+        "uncompressed" => Ok(Compression::UNCOMPRESSED),
+        other => Err(ShellError::UnsupportedInput(
+            format!(
+                "Unsupported compression '{}', expected one of: snappy, gzip, brotli, lz4, zstd, uncompressed",
+                other
+            ),
+            compression.span,
+        )),
+    }
+}
+
+fn parse_writer_version(writer_version: &Spanned<String>) -> Result<WriterVersion, ShellError> {
+    match writer_version.item.as_str() {
+        "1.0" => Ok(WriterVersion::PARQUET_1_0),
+        "2.0" => Ok(WriterVersion::PARQUET_2_0),
+        other => Err(ShellError::UnsupportedInput(
+            format!("Unsupported writer version '{}', expected 1.0 or 2.0", other),
+            writer_version.span,
+        )),
+    }
+}
+
+fn parse_max_row_group_size(max_row_group_size: &Spanned<i64>) -> Result<usize, ShellError> {
+    if max_row_group_size.item <= 0 {
+        return Err(ShellError::UnsupportedInput(
+            format!(
+                "Invalid max-row-group-size '{}', expected a positive number of rows",
+                max_row_group_size.item
+            ),
+            max_row_group_size.span,
+        ));
+    }
+
+    Ok(max_row_group_size.item as usize)
+}
+
+fn build_writer_properties(
+    compression: Option<Spanned<String>>,
+    writer_version: Option<Spanned<String>>,
+    max_row_group_size: Option<Spanned<i64>>,
+) -> Result<WriterProperties, ShellError> {
+    let mut builder = WriterProperties::builder();
+
+    if let Some(compression) = &compression {
+        builder = builder.set_compression(parse_compression(compression)?);
+    }
+
+    if let Some(writer_version) = &writer_version {
+        builder = builder.set_writer_version(parse_writer_version(writer_version)?);
+    }
+
+    if let Some(max_row_group_size) = &max_row_group_size {
+        builder = builder.set_max_row_group_size(parse_max_row_group_size(max_row_group_size)?);
     }
+
+    Ok(builder.build())
 }
 
 fn to_parquet(
     input: PipelineData,
     file: Option<Spanned<PathBuf>>,
+    props: WriterProperties,
+    schema_override: Option<SchemaRef>,
     head: Span,
     config: &Config,
 ) -> Result<PipelineData, ShellError> {
-    to_delimited_data_for_parquet(file, "CSV", input, head, config)
+    to_delimited_data_for_parquet(file, props, schema_override, "CSV", input, head, config)
 }
 
 pub fn to_delimited_data_for_parquet(
     file: Option<Spanned<PathBuf>>,
+    props: WriterProperties,
+    schema_override: Option<SchemaRef>,
     format_name: &'static str,
     input: PipelineData,
     span: Span,
     config: &Config,
 ) -> Result<PipelineData, ShellError> {
-    let value = input.into_value(span);
-    let output = match from_value_to_delimited_string(&value, config, span) {
+    match file {
+        Some(file) => {
+            // A mid-stream row-group mismatch (see `write_parquet_stream`) can make
+            // the writer bail out after it has already flushed earlier row groups
+            // without closing the file, which would otherwise leave a truncated,
+            // footer-less `.parquet` file at the target path. Write to a sibling
+            // temp path instead and only rename it over the target once the whole
+            // stream has written and closed successfully.
+            let mut temp_name = file.item.clone().into_os_string();
+            temp_name.push(".tmp");
+            let temp_path = PathBuf::from(temp_name);
+
+            let output = File::create(&temp_path).map_err(|error| {
+                ShellError::GenericError(
+                    "Could not create parquet file".to_string(),
+                    error.to_string(),
+                    Some(file.span),
+                    None,
+                    Vec::new(),
+                )
+            })?;
+
+            let result =
+                write_parquet_stream(input, output, props, schema_override, format_name, config, span);
+            if result.is_err() {
+                let _ = std::fs::remove_file(&temp_path);
+            }
+            result?;
+
+            std::fs::rename(&temp_path, &file.item).map_err(|error| {
+                ShellError::GenericError(
+                    "Could not save parquet file".to_string(),
+                    error.to_string(),
+                    Some(file.span),
+                    None,
+                    Vec::new(),
+                )
+            })?;
+            Ok(Value::string("Saved parquet file", span).into_pipeline_data())
+        }
+        None => {
+            let mut buffer: Vec<u8> = Vec::new();
+            write_parquet_stream(
+                input,
+                &mut buffer,
+                props,
+                schema_override,
+                format_name,
+                config,
+                span,
+            )?;
+            Ok(Value::Binary { val: buffer, span }.into_pipeline_data())
+        }
+    }
+}
+
+/// Write `input` as parquet to `output` without ever holding the whole table
+/// in memory twice. Rows are pulled from the pipeline in `--max-row-group-size`
+/// chunks, each chunk becomes its own row group, and the `ArrowWriter` streams
+/// every row group straight to `output` as it's built.
+///
+/// An explicit `schema_override` is used for every chunk. Otherwise the first
+/// chunk is used to infer a single Arrow type per column, which is then reused
+/// for the rest of the stream. If the first chunk is heterogeneous (mixed
+/// types, ragged columns, etc.) the whole input is instead materialized once
+/// and written through the lossy CSV roundtrip, same as before.
+fn write_parquet_stream<W: std::io::Write + Send>(
+    input: PipelineData,
+    output: W,
+    props: WriterProperties,
+    schema_override: Option<SchemaRef>,
+    format_name: &'static str,
+    config: &Config,
+    span: Span,
+) -> Result<(), ShellError> {
+    let chunk_size = props.max_row_group_size().max(1);
+    let mut rows = input.into_iter();
+
+    let first_chunk = take_chunk(&mut rows, chunk_size);
+    if first_chunk.is_empty() {
+        let schema = schema_override.unwrap_or_else(|| Arc::new(Schema::empty()));
+        let mut writer = ArrowWriter::try_new(output, schema, Some(props))
+            .map_err(|error| parquet_error_to_shell_error(error, span))?;
+        return writer
+            .close()
+            .map(|_| ())
+            .map_err(|error| parquet_error_to_shell_error(error, span));
+    }
+    let first_value = Value::List {
+        vals: first_chunk,
+        span,
+    };
+
+    // An explicit --schema always wins and every chunk (including the first)
+    // is converted through the coercing CSV roundtrip so cells can be cast to
+    // the declared type. Otherwise try to infer a single Arrow type per column
+    // from the first chunk, reusing the RecordBatch it already built instead
+    // of rebuilding it; a heterogeneous first chunk falls back to the
+    // (non-streaming) CSV roundtrip for the whole input.
+    if let Some(schema_ref) = schema_override {
+        let available = value_column_names(&first_value);
+        for field in schema_ref.fields() {
+            if !available.iter().any(|col| col == field.name()) {
+                return Err(ShellError::GenericError(
+                    format!(
+                        "Column '{}' declared in --schema was not found in the input",
+                        field.name()
+                    ),
+                    "missing column".to_string(),
+                    Some(span),
+                    None,
+                    Vec::new(),
+                ));
+            }
+        }
+
+        let mut writer = ArrowWriter::try_new(output, Arc::clone(&schema_ref), Some(props))
+            .map_err(|error| parquet_error_to_shell_error(error, span))?;
+
+        write_row_group_coerced(&schema_ref, &first_value, config, span, &mut writer)?;
+        loop {
+            let chunk = take_chunk(&mut rows, chunk_size);
+            if chunk.is_empty() {
+                break;
+            }
+            let chunk_value = Value::List { vals: chunk, span };
+            write_row_group_coerced(&schema_ref, &chunk_value, config, span, &mut writer)?;
+        }
+
+        return writer
+            .close()
+            .map(|_| ())
+            .map_err(|error| parquet_error_to_shell_error(error, span));
+    }
+
+    let first_batch = match record_batch_from_value(&first_value, span)? {
+        Some(batch) => batch,
+        None => {
+            // Heterogeneous table: fall back to the single-shot CSV roundtrip
+            // for the whole input, including the rows already pulled off above.
+            let mut all_rows = match first_value {
+                Value::List { vals, .. } => vals,
+                _ => unreachable!("constructed as a list above"),
+            };
+            all_rows.extend(rows);
+            let value = Value::List {
+                vals: all_rows,
+                span,
+            };
+
+            let csv = match from_value_to_delimited_string(&value, config, span) {
+                Ok(x) => Ok(x),
+                Err(_) => Err(ShellError::CantConvert(
+                    format_name.into(),
+                    value.get_type().to_string(),
+                    value.span().unwrap_or(span),
+                    None,
+                )),
+            }?;
+
+            return parquet_file_writer(&csv, output, props, None)
+                .map_err(|error| parquet_error_to_shell_error(error, span));
+        }
+    };
+
+    let schema = first_batch.schema();
+    let mut writer = ArrowWriter::try_new(output, Arc::clone(&schema), Some(props))
+        .map_err(|error| parquet_error_to_shell_error(error, span))?;
+
+    writer
+        .write(&first_batch)
+        .map_err(|error| parquet_error_to_shell_error(error, span))?;
+    loop {
+        let chunk = take_chunk(&mut rows, chunk_size);
+        if chunk.is_empty() {
+            break;
+        }
+        let chunk_value = Value::List { vals: chunk, span };
+        write_row_group(&schema, &chunk_value, span, &mut writer)?;
+    }
+
+    writer
+        .close()
+        .map(|_| ())
+        .map_err(|error| parquet_error_to_shell_error(error, span))
+}
+
+fn take_chunk<I: Iterator<Item = Value>>(rows: &mut I, chunk_size: usize) -> Vec<Value> {
+    let mut chunk = Vec::with_capacity(chunk_size.min(4096));
+    for _ in 0..chunk_size {
+        match rows.next() {
+            Some(value) => chunk.push(value),
+            None => break,
+        }
+    }
+    chunk
+}
+
+fn write_row_group<W: std::io::Write + Send>(
+    schema: &SchemaRef,
+    chunk_value: &Value,
+    span: Span,
+    writer: &mut ArrowWriter<W>,
+) -> Result<(), ShellError> {
+    let batch = record_batch_for_schema(schema, chunk_value, span)?;
+    writer
+        .write(&batch)
+        .map_err(|error| parquet_error_to_shell_error(error, span))
+}
+
+fn write_row_group_coerced<W: std::io::Write + Send>(
+    schema: &SchemaRef,
+    chunk_value: &Value,
+    config: &Config,
+    span: Span,
+    writer: &mut ArrowWriter<W>,
+) -> Result<(), ShellError> {
+    let batch = record_batch_for_schema_coerced(schema, chunk_value, config, span)?;
+    writer
+        .write(&batch)
+        .map_err(|error| parquet_error_to_shell_error(error, span))
+}
+
+/// Build a `RecordBatch` for one row-group chunk against a user-declared
+/// `--schema`, casting each cell to the declared type rather than requiring an
+/// exact native `Value` variant match. This stringifies the chunk and reparses
+/// it with the Arrow CSV reader pinned to `schema`, the same coercion
+/// `parquet_file_writer` already relies on for the non-streaming CSV path, so
+/// declared types that don't map to a single `Value` variant (`Int32`,
+/// `Float32`, `Decimal*`, a `Value::Int` column declared as `string`, ...)
+/// all work the same way `--schema` did before streaming was introduced.
+fn record_batch_for_schema_coerced(
+    schema: &SchemaRef,
+    chunk_value: &Value,
+    config: &Config,
+    span: Span,
+) -> Result<RecordBatch, ShellError> {
+    let row_count = match chunk_value {
+        Value::List { vals, .. } => vals.len(),
+        _ => 1,
+    };
+
+    let csv = match from_value_to_delimited_string(chunk_value, config, span) {
         Ok(x) => Ok(x),
         Err(_) => Err(ShellError::CantConvert(
-            format_name.into(),
-            value.get_type().to_string(),
-            value.span().unwrap_or(span),
+            "CSV".into(),
+            chunk_value.get_type().to_string(),
+            chunk_value.span().unwrap_or(span),
             None,
         )),
     }?;
 
-    let _why = parquet_file_writer(&output, file);
+    let mut cursor = std::io::Cursor::new(csv.as_bytes());
+    let reader = ReaderBuilder::new()
+        .has_header(true)
+        .with_delimiter(b',')
+        .with_schema(Arc::clone(schema))
+        .with_batch_size(row_count.max(1))
+        .build(&mut cursor)
+        .map_err(|error| arrow_error_to_shell_error(error, span))?;
 
-    // This works and returns nothing...
-    // Ok(Value::Nothing { span: span }.into_pipeline_data())
+    let mut batches = Vec::new();
+    for batch in reader {
+        batches.push(batch.map_err(|error| arrow_error_to_shell_error(error, span))?);
+    }
 
-    // This was the original way it worked
-    // Ok(Value::string(output, span).into_pipeline_data())
-    Ok(Value::string("Saved parquet file", span).into_pipeline_data())
+    // `with_batch_size` is set to the whole chunk's row count above, so a
+    // non-empty chunk always comes back as exactly one batch.
+    batches.pop().ok_or_else(|| {
+        ShellError::GenericError(
+            "Could not convert row group to the declared schema".to_string(),
+            "produced no rows".to_string(),
+            Some(span),
+            None,
+            Vec::new(),
+        )
+    })
 }
 
-pub fn parquet_file_writer(csv: &str, file: Option<Spanned<PathBuf>>) -> Result<(), ParquetError> {
+fn arrow_error_to_shell_error(error: arrow::error::ArrowError, span: Span) -> ShellError {
+    ShellError::GenericError(
+        "Failed to convert data to the declared schema".to_string(),
+        error.to_string(),
+        Some(span),
+        None,
+        Vec::new(),
+    )
+}
+
+/// Build a `RecordBatch` for one row-group chunk against an already-known
+/// schema (as opposed to [`record_batch_from_value`], which infers one).
+fn record_batch_for_schema(
+    schema: &SchemaRef,
+    chunk_value: &Value,
+    span: Span,
+) -> Result<RecordBatch, ShellError> {
+    let rows = match chunk_value {
+        Value::List { vals, .. } => vals,
+        _ => {
+            return Err(ShellError::GenericError(
+                "Expected a list of records for this row group".to_string(),
+                String::new(),
+                Some(span),
+                None,
+                Vec::new(),
+            ))
+        }
+    };
+
+    let mut arrays: Vec<ArrayRef> = Vec::with_capacity(schema.fields().len());
+    for field in schema.fields() {
+        let column_values: Vec<&Value> = rows
+            .iter()
+            .map(|row| match row {
+                Value::Record { cols, vals, .. } => {
+                    cols.iter().position(|col| col == field.name()).map(|idx| &vals[idx])
+                }
+                _ => None,
+            })
+            .collect::<Option<Vec<_>>>()
+            .ok_or_else(|| {
+                ShellError::GenericError(
+                    format!("Column '{}' is missing from a row", field.name()),
+                    "all rows must share the first row group's columns".to_string(),
+                    Some(span),
+                    None,
+                    Vec::new(),
+                )
+            })?;
+
+        let array = build_typed_array(field.data_type(), &column_values).ok_or_else(|| {
+            ShellError::GenericError(
+                format!(
+                    "Column '{}' does not match the expected type {:?}",
+                    field.name(),
+                    field.data_type()
+                ),
+                "all rows must share a single type per column".to_string(),
+                Some(span),
+                None,
+                Vec::new(),
+            )
+        })?;
+        arrays.push(array);
+    }
+
+    RecordBatch::try_new(Arc::clone(schema), arrays).map_err(|error| {
+        ShellError::GenericError(
+            "Could not build parquet RecordBatch".to_string(),
+            error.to_string(),
+            Some(span),
+            None,
+            Vec::new(),
+        )
+    })
+}
+
+fn parquet_error_to_shell_error(error: ParquetError, span: Span) -> ShellError {
+    ShellError::GenericError(
+        "Failed to write parquet data".to_string(),
+        error.to_string(),
+        Some(span),
+        None,
+        Vec::new(),
+    )
+}
+
+pub fn typed_parquet_file_writer<W: std::io::Write + Send>(
+    batch: &RecordBatch,
+    output: W,
+    props: WriterProperties,
+) -> Result<(), ParquetError> {
+    let mut writer = ArrowWriter::try_new(output, batch.schema(), Some(props))?;
+    writer.write(batch)?;
+    writer.close()?;
+    Ok(())
+}
+
+/// Try to build a typed Arrow `RecordBatch` straight from a `Value::List` of
+/// `Value::Record`s. Returns `Ok(None)` (rather than an error) whenever the
+/// table isn't homogeneous enough to give every column a single Arrow type,
+/// so the caller can fall back to the CSV-based writer for those cases.
+fn record_batch_from_value(
+    value: &Value,
+    span: Span,
+) -> Result<Option<RecordBatch>, ShellError> {
+    let rows = match value {
+        Value::List { vals, .. } if !vals.is_empty() => vals,
+        _ => return Ok(None),
+    };
+
+    let columns = match &rows[0] {
+        Value::Record { cols, .. } => cols.clone(),
+        _ => return Ok(None),
+    };
+
+    for row in rows {
+        match row {
+            Value::Record { cols, .. } if *cols == columns => {}
+            _ => return Ok(None),
+        }
+    }
+
+    let mut fields = Vec::with_capacity(columns.len());
+    let mut arrays: Vec<ArrayRef> = Vec::with_capacity(columns.len());
+
+    for (idx, name) in columns.iter().enumerate() {
+        let column_values: Vec<&Value> = rows
+            .iter()
+            .map(|row| match row {
+                Value::Record { vals, .. } => &vals[idx],
+                _ => unreachable!("checked above"),
+            })
+            .collect();
+
+        let data_type = match infer_arrow_type(&column_values) {
+            Some(data_type) => data_type,
+            None => return Ok(None),
+        };
+
+        let array = match build_typed_array(&data_type, &column_values) {
+            Some(array) => array,
+            None => return Ok(None),
+        };
+
+        fields.push(Field::new(name, data_type, true));
+        arrays.push(array);
+    }
+
+    let schema: SchemaRef = Arc::new(Schema::new(fields));
+    let batch = RecordBatch::try_new(schema, arrays).map_err(|error| {
+        ShellError::GenericError(
+            "Could not build parquet RecordBatch".to_string(),
+            error.to_string(),
+            Some(span),
+            None,
+            Vec::new(),
+        )
+    })?;
+
+    Ok(Some(batch))
+}
+
+fn infer_arrow_type(values: &[&Value]) -> Option<DataType> {
+    values.iter().find_map(|v| match v {
+        Value::Int { .. } => Some(DataType::Int64),
+        Value::Float { .. } => Some(DataType::Float64),
+        Value::Bool { .. } => Some(DataType::Boolean),
+        Value::Date { .. } => Some(DataType::Timestamp(TimeUnit::Millisecond, None)),
+        Value::String { .. } => Some(DataType::Utf8),
+        Value::Nothing { .. } => None,
+        _ => None,
+    })
+}
+
+fn build_typed_array(data_type: &DataType, values: &[&Value]) -> Option<ArrayRef> {
+    match data_type {
+        DataType::Int64 => {
+            let mut builder = Int64Builder::new();
+            for value in values {
+                match value {
+                    Value::Int { val, .. } => builder.append_value(*val),
+                    Value::Nothing { .. } => builder.append_null(),
+                    _ => return None,
+                }
+            }
+            Some(Arc::new(builder.finish()))
+        }
+        DataType::Float64 => {
+            let mut builder = Float64Builder::new();
+            for value in values {
+                match value {
+                    Value::Float { val, .. } => builder.append_value(*val),
+                    Value::Nothing { .. } => builder.append_null(),
+                    _ => return None,
+                }
+            }
+            Some(Arc::new(builder.finish()))
+        }
+        DataType::Boolean => {
+            let mut builder = BooleanBuilder::new();
+            for value in values {
+                match value {
+                    Value::Bool { val, .. } => builder.append_value(*val),
+                    Value::Nothing { .. } => builder.append_null(),
+                    _ => return None,
+                }
+            }
+            Some(Arc::new(builder.finish()))
+        }
+        DataType::Utf8 => {
+            let mut builder = StringBuilder::new();
+            for value in values {
+                match value {
+                    Value::String { val, .. } => builder.append_value(val),
+                    Value::Nothing { .. } => builder.append_null(),
+                    _ => return None,
+                }
+            }
+            Some(Arc::new(builder.finish()))
+        }
+        DataType::Timestamp(TimeUnit::Millisecond, None) => {
+            let mut builder = TimestampMillisecondBuilder::new();
+            for value in values {
+                match value {
+                    Value::Date { val, .. } => builder.append_value(val.timestamp_millis()),
+                    Value::Nothing { .. } => builder.append_null(),
+                    _ => return None,
+                }
+            }
+            Some(Arc::new(builder.finish()))
+        }
+        _ => None,
+    }
+}
+
+pub fn parquet_file_writer<W: std::io::Write + Send>(
+    csv: &str,
+    output: W,
+    props: WriterProperties,
+    schema_override: Option<SchemaRef>,
+) -> Result<(), ParquetError> {
     let data = csv.as_bytes();
     let mut cursor = std::io::Cursor::new(data);
 
     let delimiter: char = ',';
 
-    let schema =
-        match arrow::csv::reader::infer_file_schema(&mut cursor, delimiter as u8, None, true) {
-            Ok((schema, _inferred_has_header)) => Ok(schema),
-            Err(error) => Err(ParquetError::General(format!(
-                "Error inferring schema: {}",
-                error
-            ))),
-        }?;
-
-    let schema_ref = Arc::new(schema);
+    let schema_ref = match schema_override {
+        Some(schema_ref) => schema_ref,
+        None => {
+            let schema = match arrow::csv::reader::infer_file_schema(
+                &mut cursor,
+                delimiter as u8,
+                None,
+                true,
+            ) {
+                Ok((schema, _inferred_has_header)) => Ok(schema),
+                Err(error) => Err(ParquetError::General(format!(
+                    "Error inferring schema: {}",
+                    error
+                ))),
+            }?;
+            Arc::new(schema)
+        }
+    };
 
     let builder = ReaderBuilder::new()
         .has_header(true)
@@ -114,17 +735,7 @@ pub fn parquet_file_writer(csv: &str, file: Option<Spanned<PathBuf>>) -> Result<
 
     let reader = builder.build(cursor)?;
 
-    let output;
-    match file {
-        Some(file) => {
-            output = File::create(&file.item)?;
-        }
-        None => {
-            output = File::create("foo.parquet")?;
-        }
-    }
-
-    let mut writer = ArrowWriter::try_new(output, reader.schema(), None)?;
+    let mut writer = ArrowWriter::try_new(output, reader.schema(), Some(props))?;
 
     for batch in reader {
         match batch {
@@ -254,3 +865,257 @@ fn merge_descriptors(values: &[Value]) -> Vec<String> {
     }
     ret
 }
+
+fn value_column_names(value: &Value) -> Vec<String> {
+    match value {
+        Value::Record { cols, .. } => cols.clone(),
+        Value::List { vals, .. } => merge_descriptors(vals),
+        _ => Vec::new(),
+    }
+}
+
+/// Parse the `--schema` flag into an Arrow `SchemaRef`, either from an inline
+/// record mapping column names to type names, or from a path to a
+/// message-type schema file (mirroring `parquet-fromcsv -s/--schema`).
+fn parse_schema_override(value: Value, span: Span) -> Result<SchemaRef, ShellError> {
+    match value {
+        Value::Record { cols, vals, .. } => schema_from_record(&cols, &vals, span),
+        Value::String { val, .. } => schema_from_message_type_file(&val, span),
+        other => Err(ShellError::UnsupportedInput(
+            "Expected a record of column types or a path to a schema file".to_string(),
+            other.span().unwrap_or(span),
+        )),
+    }
+}
+
+fn schema_from_record(cols: &[String], vals: &[Value], span: Span) -> Result<SchemaRef, ShellError> {
+    let mut fields = Vec::with_capacity(cols.len());
+
+    for (name, type_value) in cols.iter().zip(vals.iter()) {
+        let type_name = match type_value {
+            Value::String { val, .. } => val.to_ascii_lowercase(),
+            other => {
+                return Err(ShellError::UnsupportedInput(
+                    format!("Expected a string type name for column '{}'", name),
+                    other.span().unwrap_or(span),
+                ))
+            }
+        };
+
+        let data_type = match type_name.as_str() {
+            "int" => DataType::Int64,
+            "double" => DataType::Float64,
+            "boolean" => DataType::Boolean,
+            "timestamp" => DataType::Timestamp(TimeUnit::Millisecond, None),
+            "string" => DataType::Utf8,
+            other => {
+                return Err(ShellError::UnsupportedInput(
+                    format!(
+                        "Unsupported schema type '{}' for column '{}', expected one of: int, string, double, boolean, timestamp",
+                        other, name
+                    ),
+                    span,
+                ))
+            }
+        };
+
+        fields.push(Field::new(name, data_type, true));
+    }
+
+    Ok(Arc::new(Schema::new(fields)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn record(cols: &[&str], vals: Vec<Value>) -> Value {
+        Value::Record {
+            cols: cols.iter().map(|s| s.to_string()).collect(),
+            vals,
+            span: Span::test_data(),
+        }
+    }
+
+    #[test]
+    fn record_batch_from_value_infers_typed_columns() {
+        let span = Span::test_data();
+        let rows = Value::List {
+            vals: vec![
+                record(
+                    &["id", "amount", "active", "note"],
+                    vec![
+                        Value::Int { val: 1, span },
+                        Value::Float { val: 1.5, span },
+                        Value::Bool { val: true, span },
+                        Value::Nothing { span },
+                    ],
+                ),
+                record(
+                    &["id", "amount", "active", "note"],
+                    vec![
+                        Value::Int { val: 2, span },
+                        Value::Float { val: 2.5, span },
+                        Value::Bool { val: false, span },
+                        Value::String {
+                            val: "hi".into(),
+                            span,
+                        },
+                    ],
+                ),
+            ],
+            span,
+        };
+
+        let batch = record_batch_from_value(&rows, span)
+            .expect("should not error")
+            .expect("homogeneous table should produce a batch");
+
+        assert_eq!(batch.num_rows(), 2);
+        assert_eq!(batch.schema().field(0).data_type(), &DataType::Int64);
+        assert_eq!(batch.schema().field(1).data_type(), &DataType::Float64);
+        assert_eq!(batch.schema().field(2).data_type(), &DataType::Boolean);
+        assert_eq!(batch.schema().field(3).data_type(), &DataType::Utf8);
+    }
+
+    #[test]
+    fn record_batch_from_value_falls_back_on_ragged_columns() {
+        let span = Span::test_data();
+        let rows = Value::List {
+            vals: vec![
+                record(&["id", "amount"], vec![Value::Int { val: 1, span }, Value::Float { val: 1.5, span }]),
+                record(&["id"], vec![Value::Int { val: 2, span }]),
+            ],
+            span,
+        };
+
+        let result = record_batch_from_value(&rows, span).expect("should not error");
+        assert!(result.is_none());
+    }
+
+    #[test]
+    fn record_batch_from_value_falls_back_on_mixed_column_types() {
+        let span = Span::test_data();
+        let rows = Value::List {
+            vals: vec![
+                record(&["id"], vec![Value::Int { val: 1, span }]),
+                record(
+                    &["id"],
+                    vec![Value::String {
+                        val: "two".into(),
+                        span,
+                    }],
+                ),
+            ],
+            span,
+        };
+
+        let result = record_batch_from_value(&rows, span).expect("should not error");
+        assert!(result.is_none());
+    }
+
+    #[test]
+    fn schema_override_coerces_int_column_to_string() {
+        let span = Span::test_data();
+        let schema: SchemaRef = Arc::new(Schema::new(vec![Field::new("zip", DataType::Utf8, true)]));
+        let chunk = Value::List {
+            vals: vec![
+                record(&["zip"], vec![Value::Int { val: 90210, span }]),
+                record(&["zip"], vec![Value::Int { val: 10001, span }]),
+            ],
+            span,
+        };
+        let config = Config::default();
+
+        let batch = record_batch_for_schema_coerced(&schema, &chunk, &config, span)
+            .expect("coercion should succeed");
+
+        assert_eq!(batch.num_rows(), 2);
+        assert_eq!(batch.schema().field(0).data_type(), &DataType::Utf8);
+        let column = batch
+            .column(0)
+            .as_any()
+            .downcast_ref::<arrow::array::StringArray>()
+            .expect("column should be a string array");
+        assert_eq!(column.value(0), "90210");
+        assert_eq!(column.value(1), "10001");
+    }
+
+    #[test]
+    fn write_parquet_stream_round_trips_typed_columns() {
+        let span = Span::test_data();
+        let rows = vec![
+            record(
+                &["id", "amount", "active"],
+                vec![
+                    Value::Int { val: 1, span },
+                    Value::Float { val: 1.5, span },
+                    Value::Bool { val: true, span },
+                ],
+            ),
+            record(
+                &["id", "amount", "active"],
+                vec![
+                    Value::Int { val: 2, span },
+                    Value::Float { val: 2.5, span },
+                    Value::Bool { val: false, span },
+                ],
+            ),
+        ];
+        let input = Value::List { vals: rows, span }.into_pipeline_data();
+        let config = Config::default();
+        let props = WriterProperties::builder().build();
+
+        let mut buffer: Vec<u8> = Vec::new();
+        write_parquet_stream(input, &mut buffer, props, None, "CSV", &config, span)
+            .expect("write should succeed");
+
+        let reader_builder =
+            parquet::arrow::arrow_reader::ParquetRecordBatchReaderBuilder::try_new(bytes::Bytes::from(
+                buffer,
+            ))
+            .expect("should build reader");
+        let reader = reader_builder.build().expect("should build record batch reader");
+        let batches: Vec<RecordBatch> = reader.collect::<Result<Vec<_>, _>>().expect("should read batches");
+        let total_rows: usize = batches.iter().map(|b| b.num_rows()).sum();
+        assert_eq!(total_rows, 2);
+        assert_eq!(batches[0].schema().field(0).data_type(), &DataType::Int64);
+        assert_eq!(batches[0].schema().field(1).data_type(), &DataType::Float64);
+        assert_eq!(batches[0].schema().field(2).data_type(), &DataType::Boolean);
+    }
+}
+
+fn schema_from_message_type_file(path: &str, span: Span) -> Result<SchemaRef, ShellError> {
+    let contents = std::fs::read_to_string(path).map_err(|error| {
+        ShellError::GenericError(
+            "Could not read schema file".to_string(),
+            error.to_string(),
+            Some(span),
+            None,
+            Vec::new(),
+        )
+    })?;
+
+    let message_type = parquet::schema::parser::parse_message_type(&contents).map_err(|error| {
+        ShellError::GenericError(
+            "Could not parse schema file".to_string(),
+            error.to_string(),
+            Some(span),
+            None,
+            Vec::new(),
+        )
+    })?;
+
+    let descriptor = parquet::schema::types::SchemaDescriptor::new(Arc::new(message_type));
+    let schema = parquet::arrow::parquet_to_arrow_schema(&descriptor, None).map_err(|error| {
+        ShellError::GenericError(
+            "Could not convert schema".to_string(),
+            error.to_string(),
+            Some(span),
+            None,
+            Vec::new(),
+        )
+    })?;
+
+    Ok(Arc::new(schema))
+}